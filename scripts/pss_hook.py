@@ -518,10 +518,10 @@ def _strip_system_reminders(text: str) -> str:
     return "".join(parts).strip()
 
 
-def should_skip_prompt(prompt: str) -> bool:
-    """Check if this prompt should skip skill suggestion."""
+def skip_reason(prompt: str) -> str | None:
+    """Return why this prompt should skip skill suggestion, or None to score it."""
     if not prompt:
-        return True
+        return "empty prompt"
 
     prompt_stripped = prompt.strip()
     prompt_lower = prompt_stripped.lower()
@@ -529,27 +529,29 @@ def should_skip_prompt(prompt: str) -> bool:
     # Skip slash commands
     for prefix in SKIP_PREFIXES:
         if prompt_stripped.startswith(prefix):
-            return True
+            return f"slash command (prefix {prefix!r})"
 
     # Skip simple one-word responses
     if prompt_lower in SKIP_SIMPLE_PROMPTS:
-        return True
+        return f"simple response {prompt_lower!r}"
 
     # Skip system-generated prompts (task notifications, session continuations,
     # hook outputs, release notes, local commands).
     # NOTE: system-reminder blocks are already stripped before this function is
     # called, so we only check for other system tags in the clean prompt.
-    if "<task-notification>" in prompt_stripped:
-        return True
-    if "<local-command-caveat>" in prompt_stripped:
-        return True
-    if "<local-command-stdout>" in prompt_stripped:
-        return True
+    for marker in ("<task-notification>", "<local-command-caveat>", "<local-command-stdout>"):
+        if marker in prompt_stripped:
+            return f"system marker {marker}"
     # Claude Code release notes pasted by /release-notes command
     if prompt_stripped.startswith("Version ") and "\n• " in prompt_stripped[:500]:
-        return True
+        return "release notes"
 
-    return False
+    return None
+
+
+def should_skip_prompt(prompt: str) -> bool:
+    """Check if this prompt should skip skill suggestion."""
+    return skip_reason(prompt) is not None
 
 
 def detect_platform() -> str:
@@ -0,0 +1,54 @@
+"""skip_reason: name the rule that makes pss_hook.py skip a prompt.
+
+should_skip_prompt is a thin wrapper over skip_reason, which returns the
+matching category (slash command, simple response, system marker, release
+notes) or None when the prompt should be scored. These tests exercise the
+helpers in isolation (no binary, no CozoDB).
+"""
+
+from __future__ import annotations
+
+import importlib.util
+import sys
+from pathlib import Path
+
+import pytest
+
+_SCRIPTS = Path(__file__).resolve().parent.parent.parent / "scripts"
+if str(_SCRIPTS) not in sys.path:
+    sys.path.insert(0, str(_SCRIPTS))
+
+
+@pytest.fixture(scope="module")
+def hook():
+    spec = importlib.util.spec_from_file_location("pss_hook", _SCRIPTS / "pss_hook.py")
+    assert spec is not None and spec.loader is not None
+    mod = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(mod)
+    return mod
+
+
+@pytest.mark.parametrize(
+    ("prompt", "expected"),
+    [
+        ("", "empty prompt"),
+        ("/help", "slash command"),
+        ("<command-name>/pss-status</command-name>", "slash command"),
+        ("ok", "simple response 'ok'"),
+        ("  Sounds Good  ", "simple response 'sounds good'"),
+        ("<task-notification>done</task-notification>", "system marker <task-notification>"),
+        ("<local-command-caveat>x</local-command-caveat>", "system marker <local-command-caveat>"),
+        ("<local-command-stdout>x</local-command-stdout>", "system marker <local-command-stdout>"),
+        ("Version 2.1.0:\n• Fixed things", "release notes"),
+    ],
+)
+def test_each_skip_category_reports_its_reason(hook, prompt, expected):
+    reason = hook.skip_reason(prompt)
+    assert reason is not None
+    assert expected in reason
+    assert hook.should_skip_prompt(prompt)
+
+
+def test_real_prompt_has_no_skip_reason(hook):
+    assert hook.skip_reason("lint python code with ruff") is None
+    assert not hook.should_skip_prompt("lint python code with ruff")